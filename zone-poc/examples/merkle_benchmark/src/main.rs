@@ -4,8 +4,10 @@
 /// Aligned with Glogos proposal v1.0.0-rc.0 §4
 
 use sha2::{Sha256, Digest};
+use blake2::Blake2s256;
 use rand::Rng;
 use rayon::prelude::*;
+use serde::{Serialize, Deserialize};
 use std::time::Instant;
 
 #[inline(always)]
@@ -15,6 +17,18 @@ fn sha256_bytes(data: &[u8]) -> [u8; 32] {
     hasher.finalize().into()
 }
 
+#[inline(always)]
+fn blake2_bytes(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Blake2s256::new();
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
+#[inline(always)]
+fn blake3_bytes(data: &[u8]) -> [u8; 32] {
+    *blake3::hash(data).as_bytes()
+}
+
 fn to_hex(bytes: &[u8]) -> String {
     hex::encode(bytes)
 }
@@ -31,39 +45,159 @@ fn format_with_commas(n: usize) -> String {
     result.chars().rev().collect()
 }
 
-fn compute_merkle_root_parallel(leaves: &[[u8; 32]]) -> [u8; 32] {
+/// Abstracts the digest behind `compute_merkle_root_parallel` and leaf
+/// generation so the stress test can measure hash throughput as an
+/// independent variable via `Workload::hasher`, instead of hardwiring sha256.
+trait HashTree {
+    const NAME: &'static str;
+    const EMPTY_ROOT_HEX: &'static str;
+    const PYTHON_THROUGHPUT_PER_SEC: f64;
+
+    fn hash(data: &[u8]) -> [u8; 32];
+
+    fn empty_root() -> [u8; 32] {
+        Self::hash(b"")
+    }
+}
+
+struct Sha256Hasher;
+
+impl HashTree for Sha256Hasher {
+    const NAME: &'static str = "sha256";
+    const EMPTY_ROOT_HEX: &'static str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+    const PYTHON_THROUGHPUT_PER_SEC: f64 = 11_456.0;
+
+    fn hash(data: &[u8]) -> [u8; 32] {
+        sha256_bytes(data)
+    }
+}
+
+struct Blake2Hasher;
+
+impl HashTree for Blake2Hasher {
+    const NAME: &'static str = "blake2s";
+    const EMPTY_ROOT_HEX: &'static str = "69217a3079908094e11121d042354a7c1f55b6482ca1a51e1b250dfd1ed0eef9";
+    const PYTHON_THROUGHPUT_PER_SEC: f64 = Sha256Hasher::PYTHON_THROUGHPUT_PER_SEC;
+
+    fn hash(data: &[u8]) -> [u8; 32] {
+        blake2_bytes(data)
+    }
+}
+
+struct Blake3Hasher;
+
+impl HashTree for Blake3Hasher {
+    const NAME: &'static str = "blake3";
+    const EMPTY_ROOT_HEX: &'static str = "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262";
+    const PYTHON_THROUGHPUT_PER_SEC: f64 = Sha256Hasher::PYTHON_THROUGHPUT_PER_SEC;
+
+    fn hash(data: &[u8]) -> [u8; 32] {
+        blake3_bytes(data)
+    }
+}
+
+fn glsr_hex(hasher: &str) -> String {
+    fn verified<H: HashTree>() -> String {
+        let glsr = to_hex(&H::empty_root());
+        assert_eq!(glsr, H::EMPTY_ROOT_HEX);
+        glsr
+    }
+
+    match hasher {
+        "blake2s" => verified::<Blake2Hasher>(),
+        "blake3" => verified::<Blake3Hasher>(),
+        _ => verified::<Sha256Hasher>(),
+    }
+}
+
+fn compute_merkle_root_parallel<H: HashTree>(leaves: &[[u8; 32]]) -> [u8; 32] {
     if leaves.is_empty() {
-        return sha256_bytes(b"");
+        return H::empty_root();
     }
     if leaves.len() == 1 {
         return leaves[0];
     }
-    
+
     let mut level = leaves.to_vec();
-    
+
     while level.len() > 1 {
         let next_level: Vec<[u8; 32]> = level.par_chunks(2)
             .map(|chunk| {
                 let left = &chunk[0];
                 // If chunk has 2 elements, use right. If 1, duplicate left.
                 let right = if chunk.len() > 1 { &chunk[1] } else { &chunk[0] };
-                
+
                 let mut combined = [0u8; 64];
                 combined[..32].copy_from_slice(left);
                 combined[32..].copy_from_slice(right);
-                sha256_bytes(&combined)
+                H::hash(&combined)
             })
             .collect();
-        
+
         level = next_level;
     }
-    
+
     level[0]
 }
 
-fn run_stress_test(leaf_count: usize) -> (f64, f64, usize) {
+// =============================================================================
+// WORKLOAD-DRIVEN HARNESS (run / summary / plot)
+// =============================================================================
+
+/// Describes a single stress-test size so that a `run` invocation is
+/// reproducible and diffable across machines instead of a one-off argv size.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Workload {
+    name: String,
+    leaf_count: usize,
+    // One of "sha256" (default), "blake2s", or "blake3" — see `HashTree`.
+    hasher: String,
+}
+
+impl Workload {
+    fn default_workload() -> Self {
+        Workload { name: "default".to_string(), leaf_count: 1_000_000, hasher: Sha256Hasher::NAME.to_string() }
+    }
+
+    fn load(path: &str) -> Self {
+        let bytes = std::fs::read(path).expect("failed to read workload file");
+        serde_json::from_slice(&bytes).expect("workload file is not valid JSON")
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct LatencySample {
+    phase: String,
+    elapsed_ms: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PhaseStats {
+    phase: String,
+    elapsed_ms: f64,
+    throughput_per_sec: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RunResult {
+    workload: Workload,
+    phases: Vec<PhaseStats>,
+    samples: Vec<LatencySample>,
+    memory_estimate_mb: usize,
+}
+
+fn run_stress_test(workload: &Workload) -> RunResult {
+    match workload.hasher.as_str() {
+        "blake2s" => run_stress_test_with::<Blake2Hasher>(workload),
+        "blake3" => run_stress_test_with::<Blake3Hasher>(workload),
+        _ => run_stress_test_with::<Sha256Hasher>(workload),
+    }
+}
+
+fn run_stress_test_with<H: HashTree>(workload: &Workload) -> RunResult {
+    let leaf_count = workload.leaf_count;
     let start = Instant::now();
-    
+
     // Phase 1: Parallel leaf generation
     let gen_start = Instant::now();
     let leaves: Vec<[u8; 32]> = (0..leaf_count)
@@ -71,48 +205,162 @@ fn run_stress_test(leaf_count: usize) -> (f64, f64, usize) {
         .map(|_| {
             let mut rng = rand::thread_rng();
             let bytes: [u8; 32] = rng.gen();
-            sha256_bytes(&bytes)
+            H::hash(&bytes)
         })
         .collect();
     let gen_time = gen_start.elapsed().as_secs_f64() * 1000.0;
-    
+
     // Phase 2: Parallel sort
     let sort_start = Instant::now();
     let mut leaves = leaves;
     leaves.par_sort_unstable();
     let sort_time = sort_start.elapsed().as_secs_f64() * 1000.0;
-    
+
     // Phase 3: Merkle root
     let merkle_start = Instant::now();
-    let root = compute_merkle_root_parallel(&leaves);
+    let root = compute_merkle_root_parallel::<H>(&leaves);
     let merkle_time = merkle_start.elapsed().as_secs_f64() * 1000.0;
-    
+
     let total = start.elapsed().as_secs_f64() * 1000.0;
-    let throughput = leaf_count as f64 / (total / 1000.0);
-    
+
     // Memory estimate (32 bytes per leaf)
     let mem_mb = (leaf_count * 32) / (1024 * 1024);
-    
+
     println!("  Generate:  {:>10.1} ms", gen_time);
     println!("  Sort:      {:>10.1} ms", sort_time);
     println!("  Merkle:    {:>10.1} ms", merkle_time);
     println!("  Root:      {}...", to_hex(&root[..8]));
-    
-    (total, throughput, mem_mb)
+
+    let phase = |name: &str, elapsed_ms: f64| PhaseStats {
+        phase: name.to_string(),
+        elapsed_ms,
+        throughput_per_sec: if elapsed_ms > 0.0 { leaf_count as f64 / (elapsed_ms / 1000.0) } else { 0.0 },
+    };
+    let samples = vec![
+        LatencySample { phase: "generate".to_string(), elapsed_ms: gen_time },
+        LatencySample { phase: "sort".to_string(), elapsed_ms: sort_time },
+        LatencySample { phase: "merkle".to_string(), elapsed_ms: merkle_time },
+        LatencySample { phase: "total".to_string(), elapsed_ms: total },
+    ];
+
+    RunResult {
+        workload: workload.clone(),
+        phases: vec![
+            phase("generate", gen_time),
+            phase("sort", sort_time),
+            phase("merkle", merkle_time),
+            phase("total", total),
+        ],
+        samples,
+        memory_estimate_mb: mem_mb,
+    }
 }
 
 fn main() {
-    // Parse command line args
     let args: Vec<String> = std::env::args().collect();
-    
-    let custom_tests: Option<Vec<usize>> = if args.len() > 1 {
-        Some(args[1..].iter()
+
+    match args.get(1).map(String::as_str) {
+        Some("run") => cmd_run(&args[2..]),
+        Some("summary") => cmd_summary(&args[2..]),
+        Some("plot") => cmd_plot(&args[2..]),
+        _ => cmd_sweep(&args[1..]),
+    }
+}
+
+fn cmd_run(args: &[String]) {
+    let mut workload_path: Option<&str> = None;
+    let mut out_path = "./results/run.json".to_string();
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--workload" => {
+                workload_path = args.get(i + 1).map(String::as_str);
+                i += 2;
+            }
+            "--out" => {
+                out_path = args.get(i + 1).cloned().unwrap_or(out_path);
+                i += 2;
+            }
+            _ => i += 1,
+        }
+    }
+
+    let workload = workload_path.map(Workload::load).unwrap_or_else(Workload::default_workload);
+
+    println!("[OK] GLSR verified ({}): {}...", workload.hasher, &glsr_hex(&workload.hasher)[..16]);
+    println!("Testing {} ({} leaves, {})...", workload.name, format_with_commas(workload.leaf_count), workload.hasher);
+    let result = run_stress_test(&workload);
+
+    if let Some(parent) = std::path::Path::new(&out_path).parent() {
+        std::fs::create_dir_all(parent).ok();
+    }
+    std::fs::write(&out_path, serde_json::to_vec_pretty(&result).unwrap()).expect("failed to write results file");
+    println!("\nResults written to {}", out_path);
+}
+
+fn cmd_summary(paths: &[String]) {
+    if paths.is_empty() {
+        eprintln!("usage: summary <result.json> [more.json...]");
+        std::process::exit(1);
+    }
+
+    println!("================================================================================");
+    println!("SUMMARY TABLE");
+    println!("================================================================================");
+    println!("| Workload        | Leaves       | Time (sec) | Throughput         | vs Solana (65K TPS) |");
+    println!("|------------------|--------------|------------|--------------------|---------------------|");
+
+    for path in paths {
+        let bytes = std::fs::read(path).expect("failed to read results file");
+        let result: RunResult = serde_json::from_slice(&bytes).expect("invalid results file");
+        let total = result.phases.iter().find(|p| p.phase == "total").cloned().unwrap_or(PhaseStats {
+            phase: "total".to_string(),
+            elapsed_ms: 0.0,
+            throughput_per_sec: 0.0,
+        });
+        println!(
+            "| {:<16} | {:>12} | {:>10.2} | {:>18.0} | {:>17.1}x |",
+            result.workload.name,
+            format_with_commas(result.workload.leaf_count),
+            total.elapsed_ms / 1000.0,
+            total.throughput_per_sec,
+            total.throughput_per_sec / 65000.0
+        );
+    }
+    println!("================================================================================");
+}
+
+fn cmd_plot(paths: &[String]) {
+    let path = match paths.first() {
+        Some(p) => p,
+        None => {
+            eprintln!("usage: plot <result.json>");
+            std::process::exit(1);
+        }
+    };
+
+    let bytes = std::fs::read(path).expect("failed to read results file");
+    let result: RunResult = serde_json::from_slice(&bytes).expect("invalid results file");
+
+    println!("Latency over time for workload '{}':", result.workload.name);
+    for phase in &result.phases {
+        let values: Vec<f64> = result.samples.iter()
+            .filter(|s| s.phase == phase.phase)
+            .map(|s| s.elapsed_ms)
+            .collect();
+        println!("  {:<8} {:>10.1} ms", phase.phase, values.first().copied().unwrap_or(0.0));
+    }
+}
+
+fn cmd_sweep(args: &[String]) {
+    let custom_tests: Option<Vec<usize>> = if !args.is_empty() {
+        Some(args.iter()
             .filter_map(|s| s.replace("_", "").replace(",", "").parse().ok())
             .collect())
     } else {
         None
     };
-    
+
     println!("================================================================================");
     println!("GLOGOS STRESS TEST - PUSHING THE LIMITS");
     println!("================================================================================");
@@ -121,52 +369,54 @@ fn main() {
         println!("Custom test sizes: {:?}", custom_tests.as_ref().unwrap());
     }
     println!();
-    
+
     // Verify GLSR
-    let glsr = to_hex(&sha256_bytes(b""));
-    assert_eq!(glsr, "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
-    println!("[OK] GLSR verified");
+    let glsr = to_hex(&Sha256Hasher::empty_root());
+    assert_eq!(glsr, Sha256Hasher::EMPTY_ROOT_HEX);
+    println!("[OK] GLSR verified ({})", Sha256Hasher::NAME);
     println!();
-    
+
     // Warmup
     println!("Warming up...");
-    let _ = run_stress_test(100_000);
+    let _ = run_stress_test(&Workload { name: "warmup".to_string(), leaf_count: 100_000, hasher: Sha256Hasher::NAME.to_string() });
     println!();
-    
+
     let default_tests = vec![
         1_000_000,
         10_000_000,
         50_000_000,
         100_000_000,
     ];
-    
+
     let tests = custom_tests.unwrap_or(default_tests);
-    
+
     println!("================================================================================");
     println!("STRESS TEST RESULTS");
     println!("================================================================================");
-    
+
     let mut results = Vec::new();
-    
+
     for &count in &tests {
         println!();
         println!("Testing {} attestations...", format_with_commas(count));
         println!("----------------------------------------");
-        let (total_ms, throughput, mem_mb) = run_stress_test(count);
+        let workload = Workload { name: format!("sweep-{}", count), leaf_count: count, hasher: Sha256Hasher::NAME.to_string() };
+        let result = run_stress_test(&workload);
+        let total = result.phases.iter().find(|p| p.phase == "total").unwrap();
         println!("----------------------------------------");
-        println!("  TOTAL:     {:>10.1} ms ({:.2} sec)", total_ms, total_ms / 1000.0);
-        println!("  THROUGHPUT:{:>10.0} attestations/sec", throughput);
-        println!("  MEMORY:    {:>10} MB (leaves only)", mem_mb);
-        results.push((count, total_ms, throughput));
+        println!("  TOTAL:     {:>10.1} ms ({:.2} sec)", total.elapsed_ms, total.elapsed_ms / 1000.0);
+        println!("  THROUGHPUT:{:>10.0} attestations/sec", total.throughput_per_sec);
+        println!("  MEMORY:    {:>10} MB (leaves only)", result.memory_estimate_mb);
+        results.push((count, total.elapsed_ms, total.throughput_per_sec));
     }
-    
+
     println!();
     println!("================================================================================");
     println!("SUMMARY TABLE");
     println!("================================================================================");
     println!("| Attestations | Time (sec) | Throughput         | vs Solana (65K TPS) |");
     println!("|--------------|------------|--------------------|---------------------|");
-    
+
     for (count, time_ms, throughput) in &results {
         let ratio = throughput / 65000.0;
         println!(
@@ -177,7 +427,7 @@ fn main() {
             ratio
         );
     }
-    
+
     println!();
     println!("================================================================================");
     println!("PEAK PERFORMANCE");
@@ -185,6 +435,6 @@ fn main() {
     let best = results.iter().max_by(|a, b| a.2.partial_cmp(&b.2).unwrap()).unwrap();
     println!("  Best throughput: {:.0} attestations/sec @ {} attestations", best.2, format_with_commas(best.0));
     println!("  vs Solana: {:.1}x faster (no consensus)", best.2 / 65000.0);
-    println!("  vs Python: {:.0}x faster", best.2 / 11456.0);
+    println!("  vs Python: {:.0}x faster", best.2 / Sha256Hasher::PYTHON_THROUGHPUT_PER_SEC);
     println!("================================================================================");
 }